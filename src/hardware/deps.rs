@@ -0,0 +1,265 @@
+//! Dependency resolution — a manifest can require other installed plugins
+//! at a semver range (`[dependencies]`), so composite workflows fail fast
+//! with a clear message instead of mid-run.
+
+use std::collections::{HashMap, HashSet};
+use std::fmt;
+
+use semver::Version;
+
+use super::loader::WithPath;
+use super::manifest::ToolManifest;
+
+/// Why a declared dependency couldn't be satisfied.
+#[derive(Debug, PartialEq, Eq)]
+pub enum DependencyStatus {
+    /// No installed tool has this name.
+    Missing,
+    /// The dependency is installed, but its `tool.version` isn't valid
+    /// semver, so it can't be checked against the required range.
+    InvalidVersion(String),
+    /// The dependency is installed with a valid version that doesn't
+    /// satisfy the required range.
+    Unsatisfied(String),
+}
+
+/// One unsatisfied dependency of a tool in the registry.
+#[derive(Debug, PartialEq, Eq)]
+pub struct UnsatisfiedDependency {
+    /// Name of the tool that declared the dependency.
+    pub tool: String,
+    /// Name of the required dependency.
+    pub dependency: String,
+    /// The semver range the manifest requires, as written.
+    pub required: String,
+    /// Why the dependency wasn't satisfied.
+    pub status: DependencyStatus,
+}
+
+impl fmt::Display for UnsatisfiedDependency {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.status {
+            DependencyStatus::Missing => write!(
+                f,
+                "{} requires {} {} but it is missing",
+                self.tool, self.dependency, self.required
+            ),
+            DependencyStatus::InvalidVersion(raw) => write!(
+                f,
+                "{} requires {} {} but its installed version `{raw}` isn't valid semver",
+                self.tool, self.dependency, self.required
+            ),
+            DependencyStatus::Unsatisfied(found) => write!(
+                f,
+                "{} requires {} {} but found {found}",
+                self.tool, self.dependency, self.required
+            ),
+        }
+    }
+}
+
+impl std::error::Error for UnsatisfiedDependency {}
+
+/// Checks every tool's `[dependencies]` against the versions of the other
+/// tools in `registry`, parsing each `tool.version` at resolution time.
+/// Returns every unsatisfied dependency found, grouped by the tool that
+/// declared it; tools with no unsatisfied dependencies are omitted.
+pub fn resolve_dependencies(
+    registry: &[WithPath<ToolManifest>],
+) -> HashMap<String, Vec<UnsatisfiedDependency>> {
+    let installed: HashMap<&str, Result<Version, String>> = registry
+        .iter()
+        .map(|entry| {
+            let meta = &entry.inner.tool;
+            (meta.name.as_str(), meta.semver().map_err(|_| meta.version.clone()))
+        })
+        .collect();
+
+    let mut unsatisfied_by_tool = HashMap::new();
+    for entry in registry {
+        let manifest = &entry.inner;
+        let mut unsatisfied = Vec::new();
+        for (dep_name, req) in &manifest.dependencies {
+            let status = match installed.get(dep_name.as_str()) {
+                None => DependencyStatus::Missing,
+                Some(Err(raw_version)) => DependencyStatus::InvalidVersion(raw_version.clone()),
+                Some(Ok(version)) if req.matches(version) => continue,
+                Some(Ok(version)) => DependencyStatus::Unsatisfied(version.to_string()),
+            };
+            unsatisfied.push(UnsatisfiedDependency {
+                tool: manifest.tool.name.clone(),
+                dependency: dep_name.clone(),
+                required: req.to_string(),
+                status,
+            });
+        }
+        if !unsatisfied.is_empty() {
+            unsatisfied_by_tool.insert(manifest.tool.name.clone(), unsatisfied);
+        }
+    }
+    unsatisfied_by_tool
+}
+
+/// A dependency cycle found while computing topological order, listed from
+/// the tool that starts the cycle back around to itself.
+#[derive(Debug, PartialEq, Eq)]
+pub struct DependencyCycle(pub Vec<String>);
+
+impl fmt::Display for DependencyCycle {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "dependency cycle: {}", self.0.join(" -> "))
+    }
+}
+
+impl std::error::Error for DependencyCycle {}
+
+/// Computes a load/activation order for `registry` such that every tool
+/// appears after the dependencies it declares (dependencies on tools outside
+/// the registry are ignored — [`resolve_dependencies`] reports those).
+/// Returns the cycle path if the dependency graph isn't acyclic.
+pub fn topological_order(registry: &[WithPath<ToolManifest>]) -> Result<Vec<String>, DependencyCycle> {
+    let names: HashSet<&str> = registry.iter().map(|e| e.inner.tool.name.as_str()).collect();
+    let graph: HashMap<&str, Vec<&str>> = registry
+        .iter()
+        .map(|entry| {
+            let deps = entry
+                .inner
+                .dependencies
+                .keys()
+                .map(String::as_str)
+                .filter(|dep| names.contains(dep))
+                .collect();
+            (entry.inner.tool.name.as_str(), deps)
+        })
+        .collect();
+
+    let mut order = Vec::new();
+    let mut visited = HashSet::new();
+    let mut stack = Vec::new();
+    for name in registry.iter().map(|e| e.inner.tool.name.as_str()) {
+        visit(name, &graph, &mut visited, &mut stack, &mut order)?;
+    }
+    Ok(order)
+}
+
+fn visit<'a>(
+    name: &'a str,
+    graph: &HashMap<&'a str, Vec<&'a str>>,
+    visited: &mut HashSet<&'a str>,
+    stack: &mut Vec<&'a str>,
+    order: &mut Vec<String>,
+) -> Result<(), DependencyCycle> {
+    if visited.contains(name) {
+        return Ok(());
+    }
+    if let Some(pos) = stack.iter().position(|n| *n == name) {
+        let mut cycle: Vec<String> = stack[pos..].iter().map(|n| n.to_string()).collect();
+        cycle.push(name.to_string());
+        return Err(DependencyCycle(cycle));
+    }
+    stack.push(name);
+    for dep in graph.get(name).into_iter().flatten() {
+        visit(dep, graph, visited, stack, order)?;
+    }
+    stack.pop();
+    visited.insert(name);
+    order.push(name.to_string());
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::path::PathBuf;
+
+    fn tool(name: &str, version: &str, dependencies: &[(&str, &str)]) -> WithPath<ToolManifest> {
+        let deps = dependencies
+            .iter()
+            .map(|(dep, req)| format!("{dep} = \"{req}\""))
+            .collect::<Vec<_>>()
+            .join("\n");
+        let raw = format!(
+            r#"
+[tool]
+name = "{name}"
+version = "{version}"
+description = "test tool"
+
+[exec]
+binary = "run"
+
+[dependencies]
+{deps}
+"#
+        );
+        WithPath {
+            inner: ToolManifest::from_slice(&raw).expect("parse failed"),
+            path: PathBuf::from(format!("{name}/tool.toml")),
+        }
+    }
+
+    #[test]
+    fn resolve_dependencies_accepts_satisfied_range() {
+        let registry = vec![
+            tool("swd_reset", "1.2.0", &[]),
+            tool("flash_firmware", "1.0.0", &[("swd_reset", ">=1.2")]),
+        ];
+        assert!(resolve_dependencies(&registry).is_empty());
+    }
+
+    #[test]
+    fn resolve_dependencies_reports_missing_and_unsatisfied() {
+        let registry = vec![
+            tool("swd_reset", "1.1.0", &[]),
+            tool("flash_firmware", "1.0.0", &[("swd_reset", ">=1.2"), ("logger", ">=1.0")]),
+        ];
+        let unsatisfied = resolve_dependencies(&registry);
+        let flash = &unsatisfied["flash_firmware"];
+        assert_eq!(flash.len(), 2);
+        let swd = flash.iter().find(|d| d.dependency == "swd_reset").unwrap();
+        assert_eq!(swd.status, DependencyStatus::Unsatisfied("1.1.0".to_string()));
+        let logger = flash.iter().find(|d| d.dependency == "logger").unwrap();
+        assert_eq!(logger.status, DependencyStatus::Missing);
+    }
+
+    #[test]
+    fn resolve_dependencies_distinguishes_invalid_version_from_missing() {
+        let registry = vec![
+            tool("swd_reset", "not-a-semver", &[]),
+            tool("flash_firmware", "1.0.0", &[("swd_reset", ">=1.2")]),
+        ];
+        let unsatisfied = resolve_dependencies(&registry);
+        let swd = unsatisfied["flash_firmware"]
+            .iter()
+            .find(|d| d.dependency == "swd_reset")
+            .unwrap();
+        assert_eq!(
+            swd.status,
+            DependencyStatus::InvalidVersion("not-a-semver".to_string())
+        );
+        assert!(!swd.to_string().contains("is missing"));
+    }
+
+    #[test]
+    fn topological_order_places_dependencies_before_dependents() {
+        let registry = vec![
+            tool("flash_firmware", "1.0.0", &[("swd_reset", ">=1.0")]),
+            tool("swd_reset", "1.2.0", &[]),
+        ];
+        let order = topological_order(&registry).expect("expected an order");
+        let swd_pos = order.iter().position(|n| n == "swd_reset").unwrap();
+        let flash_pos = order.iter().position(|n| n == "flash_firmware").unwrap();
+        assert!(swd_pos < flash_pos);
+    }
+
+    #[test]
+    fn topological_order_detects_cycles() {
+        let registry = vec![
+            tool("a", "1.0.0", &[("b", ">=1.0")]),
+            tool("b", "1.0.0", &[("a", ">=1.0")]),
+        ];
+        let err = topological_order(&registry).unwrap_err();
+        assert!(err.0.contains(&"a".to_string()));
+        assert!(err.0.contains(&"b".to_string()));
+    }
+}
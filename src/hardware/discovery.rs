@@ -0,0 +1,166 @@
+//! Hardware discovery — resolves the free-form capability tags a tool
+//! declares in `[[requirements]]` against whatever is actually plugged in.
+//!
+//! Before a tool is invoked, ZeroClaw runs the transport's configured
+//! `discovery_probe` binary/script. Its stdout must be a JSON object mapping
+//! each capability tag to a device descriptor (port path, baud, bus number,
+//! serial number, ...). That report is matched against the tool's declared
+//! [`HardwareRequirement`]s; resolved descriptors are then merged into the
+//! parameter map handed to the tool over stdin, keyed by tag.
+
+use std::collections::HashMap;
+use std::fmt;
+use std::process::Command;
+
+use serde::Deserialize;
+
+use super::manifest::HardwareRequirement;
+
+/// Raw output of a discovery probe: capability tag → device descriptor.
+#[derive(Debug, Clone, Deserialize)]
+pub struct DiscoveryReport(pub HashMap<String, serde_json::Value>);
+
+impl DiscoveryReport {
+    /// Parses a probe's stdout into a report.
+    pub fn from_json(raw: &str) -> Result<Self, serde_json::Error> {
+        serde_json::from_str(raw)
+    }
+
+    /// Runs `probe_binary` and parses its stdout as a report.
+    pub fn from_probe(probe_binary: &str) -> Result<Self, DiscoveryError> {
+        let output = Command::new(probe_binary)
+            .output()
+            .map_err(|e| DiscoveryError::ProbeFailed(probe_binary.to_string(), e.to_string()))?;
+        if !output.status.success() {
+            return Err(DiscoveryError::ProbeFailed(
+                probe_binary.to_string(),
+                format!("exited with {}", output.status),
+            ));
+        }
+        let stdout = String::from_utf8_lossy(&output.stdout);
+        Self::from_json(&stdout).map_err(|e| DiscoveryError::InvalidReport(e.to_string()))
+    }
+}
+
+/// Error running or parsing a discovery probe.
+#[derive(Debug)]
+pub enum DiscoveryError {
+    /// The probe binary could not be spawned or exited non-zero.
+    ProbeFailed(String, String),
+    /// The probe's stdout was not a valid `DiscoveryReport`.
+    InvalidReport(String),
+}
+
+impl fmt::Display for DiscoveryError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::ProbeFailed(bin, reason) => write!(f, "discovery probe `{bin}` failed: {reason}"),
+            Self::InvalidReport(reason) => write!(f, "invalid discovery report: {reason}"),
+        }
+    }
+}
+
+impl std::error::Error for DiscoveryError {}
+
+/// Capability tags declared by a tool that the discovery report could not
+/// satisfy.
+#[derive(Debug, PartialEq, Eq)]
+pub struct UnmetRequirements {
+    /// Tags with no matching entry in the discovery report.
+    pub missing: Vec<String>,
+}
+
+impl fmt::Display for UnmetRequirements {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unmet hardware requirements: {}", self.missing.join(", "))
+    }
+}
+
+impl std::error::Error for UnmetRequirements {}
+
+/// Matches `requirements` against `report`, returning the resolved bindings
+/// (tag → descriptor) or the list of unmet non-optional tags.
+pub fn match_requirements(
+    requirements: &[HardwareRequirement],
+    report: &DiscoveryReport,
+) -> Result<HashMap<String, serde_json::Value>, UnmetRequirements> {
+    let mut resolved = HashMap::new();
+    let mut missing = Vec::new();
+    for req in requirements {
+        match report.0.get(&req.tag) {
+            Some(descriptor) => {
+                resolved.insert(req.tag.clone(), descriptor.clone());
+            }
+            None if req.optional => {}
+            None => missing.push(req.tag.clone()),
+        }
+    }
+    if missing.is_empty() {
+        Ok(resolved)
+    } else {
+        Err(UnmetRequirements { missing })
+    }
+}
+
+/// Merges resolved hardware bindings into the parameter map passed to a tool
+/// over stdin, keyed by capability tag (e.g. a tool requesting `"I2C-bus"`
+/// finds its descriptor at `params["I2C-bus"]`).
+pub fn inject_bindings(
+    params: &mut serde_json::Map<String, serde_json::Value>,
+    bindings: &HashMap<String, serde_json::Value>,
+) {
+    for (tag, descriptor) in bindings {
+        params.insert(tag.clone(), descriptor.clone());
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn req(tag: &str, optional: bool) -> HardwareRequirement {
+        HardwareRequirement {
+            tag: tag.to_string(),
+            optional,
+        }
+    }
+
+    #[test]
+    fn report_parses_json_descriptors() {
+        let raw = r#"{"I2C-bus": {"bus": 1, "port": "/dev/i2c-1"}}"#;
+        let report = DiscoveryReport::from_json(raw).expect("parse failed");
+        assert_eq!(report.0["I2C-bus"]["bus"], serde_json::json!(1));
+    }
+
+    #[test]
+    fn match_requirements_resolves_present_tags() {
+        let report = DiscoveryReport::from_json(r#"{"SWD-target": {"serial": "1234"}}"#).unwrap();
+        let resolved = match_requirements(&[req("SWD-target", false)], &report).unwrap();
+        assert_eq!(resolved["SWD-target"]["serial"], serde_json::json!("1234"));
+    }
+
+    #[test]
+    fn match_requirements_ignores_missing_optional_tags() {
+        let report = DiscoveryReport::from_json("{}").unwrap();
+        let resolved = match_requirements(&[req("UART-loopback", true)], &report).unwrap();
+        assert!(resolved.is_empty());
+    }
+
+    #[test]
+    fn match_requirements_reports_missing_required_tags() {
+        let report = DiscoveryReport::from_json("{}").unwrap();
+        let err = match_requirements(&[req("I2C-bus", false)], &report).unwrap_err();
+        assert_eq!(err.missing, vec!["I2C-bus".to_string()]);
+    }
+
+    #[test]
+    fn inject_bindings_merges_descriptors_by_tag() {
+        let mut params = serde_json::Map::new();
+        params.insert("device".to_string(), serde_json::json!("pico0"));
+        let mut bindings = HashMap::new();
+        bindings.insert("I2C-bus".to_string(), serde_json::json!({"bus": 0}));
+        inject_bindings(&mut params, &bindings);
+        assert_eq!(params["device"], serde_json::json!("pico0"));
+        assert_eq!(params["I2C-bus"]["bus"], serde_json::json!(0));
+    }
+}
@@ -0,0 +1,434 @@
+//! Templated tool invocation — expands `{{param}}` placeholders in
+//! `exec.args`, `exec.env`, and `exec.working_dir` against the resolved
+//! parameter map, runs the child with `exec.timeout_secs` enforced, and
+//! runs single `[[hooks]]` commands ([`run_hook`], which does not look at a
+//! hook's `when`).
+
+use std::collections::HashMap;
+use std::fmt;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+use std::process::{Command, Output, Stdio};
+use std::time::{Duration, Instant};
+
+use super::manifest::{ExecConfig, HookConfig};
+
+/// Error expanding a `{{...}}` template.
+#[derive(Debug, PartialEq, Eq)]
+pub enum TemplateError {
+    /// A placeholder referenced a parameter (or nested field) that wasn't
+    /// present in the resolved parameter map.
+    UnknownPlaceholder(String),
+    /// A `{{` was never closed by a matching `}}`.
+    UnterminatedPlaceholder,
+}
+
+impl fmt::Display for TemplateError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::UnknownPlaceholder(key) => write!(f, "unknown template placeholder `{{{{{key}}}}}`"),
+            Self::UnterminatedPlaceholder => write!(f, "unterminated `{{{{` in template"),
+        }
+    }
+}
+
+impl std::error::Error for TemplateError {}
+
+/// Expands `{{...}}` placeholders in `template` against `params`.
+///
+/// A placeholder may reference a top-level parameter (`{{device}}`) or, with
+/// dot notation, a nested field of an object value (`{{device.port}}`) —
+/// the shape a discovery descriptor merged into `params` takes.
+pub fn render_template(
+    template: &str,
+    params: &serde_json::Map<String, serde_json::Value>,
+) -> Result<String, TemplateError> {
+    let mut out = String::with_capacity(template.len());
+    let mut rest = template;
+    while let Some(start) = rest.find("{{") {
+        out.push_str(&rest[..start]);
+        let after_open = &rest[start + 2..];
+        let end = after_open
+            .find("}}")
+            .ok_or(TemplateError::UnterminatedPlaceholder)?;
+        let key = after_open[..end].trim();
+        let value = lookup(params, key).ok_or_else(|| TemplateError::UnknownPlaceholder(key.to_string()))?;
+        out.push_str(&value_to_template_string(value));
+        rest = &after_open[end + 2..];
+    }
+    out.push_str(rest);
+    Ok(out)
+}
+
+fn lookup<'a>(
+    params: &'a serde_json::Map<String, serde_json::Value>,
+    key: &str,
+) -> Option<&'a serde_json::Value> {
+    let mut segments = key.split('.');
+    let mut current = params.get(segments.next()?)?;
+    for segment in segments {
+        current = current.as_object()?.get(segment)?;
+    }
+    Some(current)
+}
+
+fn value_to_template_string(value: &serde_json::Value) -> String {
+    match value {
+        serde_json::Value::String(s) => s.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// An `ExecConfig` with every placeholder expanded and every path resolved
+/// against the plugin directory, ready to spawn.
+#[derive(Debug, PartialEq, Eq)]
+pub struct RenderedExec {
+    pub binary: PathBuf,
+    pub args: Vec<String>,
+    pub env: HashMap<String, String>,
+    pub working_dir: Option<PathBuf>,
+    pub timeout_secs: Option<u64>,
+}
+
+/// Renders every templated field of `exec` against `params`, resolving
+/// `binary` and `working_dir` relative to `plugin_dir` (the directory
+/// containing the manifest's `tool.toml`) per `ExecConfig::binary`'s
+/// contract.
+///
+/// `params` should already have defaults filled in (see
+/// [`crate::hardware::manifest::ToolManifest::fill_defaults`]) and any
+/// resolved hardware bindings merged in (see
+/// [`crate::hardware::discovery::inject_bindings`]) — this function only
+/// substitutes, it doesn't fill defaults itself.
+pub fn render_exec(
+    exec: &ExecConfig,
+    plugin_dir: &Path,
+    params: &serde_json::Map<String, serde_json::Value>,
+) -> Result<RenderedExec, TemplateError> {
+    let args = exec
+        .args
+        .iter()
+        .map(|arg| render_template(arg, params))
+        .collect::<Result<Vec<_>, _>>()?;
+    let mut env = HashMap::with_capacity(exec.env.len());
+    for (key, value) in &exec.env {
+        env.insert(key.clone(), render_template(value, params)?);
+    }
+    let working_dir = exec
+        .working_dir
+        .as_deref()
+        .map(|dir| render_template(dir, params))
+        .transpose()?
+        .map(|dir| plugin_dir.join(dir));
+    Ok(RenderedExec {
+        binary: plugin_dir.join(&exec.binary),
+        args,
+        env,
+        working_dir,
+        timeout_secs: exec.timeout_secs,
+    })
+}
+
+/// Spawns `rendered`, writing `params` as JSON to its stdin (per
+/// `ParameterDef.name`'s doc contract — "matches the JSON key passed to the
+/// tool via stdin") and killing it if it outlives `timeout_secs`.
+///
+/// Returns an `InvalidInput` error, without spawning anything, if
+/// `timeout_secs` is too large to schedule a deadline for.
+pub fn run_with_timeout(
+    rendered: &RenderedExec,
+    params: &serde_json::Map<String, serde_json::Value>,
+) -> io::Result<Output> {
+    let deadline = rendered
+        .timeout_secs
+        .map(|timeout_secs| {
+            Instant::now()
+                .checked_add(Duration::from_secs(timeout_secs))
+                .ok_or_else(|| {
+                    io::Error::new(
+                        io::ErrorKind::InvalidInput,
+                        format!("timeout_secs ({timeout_secs}) is too large to schedule"),
+                    )
+                })
+        })
+        .transpose()?;
+
+    let mut command = Command::new(&rendered.binary);
+    command.args(&rendered.args).envs(&rendered.env);
+    if let Some(dir) = &rendered.working_dir {
+        command.current_dir(dir);
+    }
+    command
+        .stdin(Stdio::piped())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped());
+
+    let mut child = command.spawn()?;
+    {
+        let mut stdin = child.stdin.take().expect("stdin was piped");
+        let json = serde_json::to_vec(params)
+            .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+        stdin.write_all(&json)?;
+    } // dropping the handle closes the pipe so the child sees EOF.
+
+    let Some(deadline) = deadline else {
+        return child.wait_with_output();
+    };
+
+    loop {
+        if child.try_wait()?.is_some() {
+            return child.wait_with_output();
+        }
+        if Instant::now() >= deadline {
+            child.kill()?;
+            child.wait()?;
+            return Err(io::Error::new(
+                io::ErrorKind::TimedOut,
+                format!(
+                    "tool exceeded timeout_secs ({}s)",
+                    rendered.timeout_secs.unwrap_or_default()
+                ),
+            ));
+        }
+        std::thread::sleep(Duration::from_millis(20));
+    }
+}
+
+/// Error running a `[[hooks]]` command.
+#[derive(Debug)]
+pub enum HookError {
+    /// One of the hook's `args` failed to render as a template.
+    Template(TemplateError),
+    /// The hook command could not be spawned.
+    Spawn(String, io::Error),
+}
+
+impl fmt::Display for HookError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Template(e) => write!(f, "{e}"),
+            Self::Spawn(command, e) => write!(f, "failed to run hook `{command}`: {e}"),
+        }
+    }
+}
+
+impl std::error::Error for HookError {}
+
+/// Runs a single `[[hooks]]` command with its `args` templated against
+/// `params` and `command` resolved relative to `plugin_dir`.
+pub fn run_hook(
+    hook: &HookConfig,
+    plugin_dir: &Path,
+    params: &serde_json::Map<String, serde_json::Value>,
+) -> Result<Output, HookError> {
+    let args = hook
+        .args
+        .iter()
+        .map(|arg| render_template(arg, params))
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(HookError::Template)?;
+    let command = plugin_dir.join(&hook.command);
+    Command::new(&command)
+        .args(&args)
+        .output()
+        .map_err(|e| HookError::Spawn(command.display().to_string(), e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn params(pairs: &[(&str, serde_json::Value)]) -> serde_json::Map<String, serde_json::Value> {
+        pairs.iter().map(|(k, v)| (k.to_string(), v.clone())).collect()
+    }
+
+    fn scratch_plugin_dir(label: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("zeroclaw-exec-test-{label}"));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn render_template_substitutes_top_level_and_nested_params() {
+        let p = params(&[
+            ("image", serde_json::json!("firmware.bin")),
+            ("device", serde_json::json!({"port": "/dev/ttyUSB0"})),
+        ]);
+        let rendered =
+            render_template("--port {{device.port}} --image {{image}}", &p).expect("render failed");
+        assert_eq!(rendered, "--port /dev/ttyUSB0 --image firmware.bin");
+    }
+
+    #[test]
+    fn render_template_errors_on_missing_key() {
+        let p = params(&[]);
+        let err = render_template("{{missing}}", &p).unwrap_err();
+        assert_eq!(err, TemplateError::UnknownPlaceholder("missing".to_string()));
+    }
+
+    #[test]
+    fn render_template_errors_on_missing_nested_key() {
+        let p = params(&[("device", serde_json::json!({"port": "/dev/ttyUSB0"}))]);
+        let err = render_template("{{device.baud}}", &p).unwrap_err();
+        assert_eq!(
+            err,
+            TemplateError::UnknownPlaceholder("device.baud".to_string())
+        );
+    }
+
+    #[test]
+    fn render_exec_uses_manifest_fill_defaults_for_an_omitted_param() {
+        use super::super::manifest::ToolManifest;
+
+        let manifest: ToolManifest = toml::from_str(
+            r#"
+[tool]
+name        = "pwm_set"
+version     = "1.0.0"
+description = "Set PWM duty cycle on a pin"
+
+[exec]
+binary = "pwm_set"
+args   = ["--duty", "{{duty}}"]
+
+[[parameters]]
+name        = "duty"
+type        = "integer"
+description = "Duty cycle 0-100"
+required    = false
+default     = 50
+"#,
+        )
+        .expect("parse failed");
+
+        // The caller (e.g. the LLM) omitted `duty` entirely.
+        let mut p = params(&[]);
+        manifest.fill_defaults(&mut p);
+
+        let plugin_dir = scratch_plugin_dir("fill-defaults");
+        let rendered = render_exec(&manifest.exec, &plugin_dir, &p).expect("render failed");
+        assert_eq!(rendered.args, vec!["--duty".to_string(), "50".to_string()]);
+    }
+
+    #[test]
+    fn render_template_passes_through_text_without_placeholders() {
+        let p = params(&[]);
+        assert_eq!(render_template("no placeholders here", &p).unwrap(), "no placeholders here");
+    }
+
+    #[test]
+    fn render_exec_expands_args_env_and_resolves_binary_and_working_dir_in_plugin_dir() {
+        let plugin_dir = scratch_plugin_dir("render");
+        let exec = ExecConfig {
+            binary: "flash_firmware".to_string(),
+            args: vec!["--port".to_string(), "{{device.port}}".to_string()],
+            env: HashMap::from([("LEVEL".to_string(), "{{level}}".to_string())]),
+            working_dir: Some("subdir".to_string()),
+            timeout_secs: Some(5),
+        };
+        let p = params(&[
+            ("device", serde_json::json!({"port": "/dev/ttyUSB0"})),
+            ("level", serde_json::json!("debug")),
+        ]);
+        let rendered = render_exec(&exec, &plugin_dir, &p).expect("render failed");
+        assert_eq!(rendered.binary, plugin_dir.join("flash_firmware"));
+        assert_eq!(rendered.args, vec!["--port".to_string(), "/dev/ttyUSB0".to_string()]);
+        assert_eq!(rendered.env.get("LEVEL"), Some(&"debug".to_string()));
+        assert_eq!(rendered.working_dir, Some(plugin_dir.join("subdir")));
+    }
+
+    #[test]
+    fn render_exec_propagates_missing_key_error() {
+        let plugin_dir = scratch_plugin_dir("missing-key");
+        let exec = ExecConfig {
+            binary: "noop".to_string(),
+            args: vec!["{{missing}}".to_string()],
+            env: HashMap::new(),
+            working_dir: None,
+            timeout_secs: None,
+        };
+        let err = render_exec(&exec, &plugin_dir, &params(&[])).unwrap_err();
+        assert_eq!(err, TemplateError::UnknownPlaceholder("missing".to_string()));
+    }
+
+    fn write_script(plugin_dir: &Path, name: &str, body: &str) -> PathBuf {
+        let script_path = plugin_dir.join(name);
+        fs::write(&script_path, body).unwrap();
+        let mut perms = fs::metadata(&script_path).unwrap().permissions();
+        std::os::unix::fs::PermissionsExt::set_mode(&mut perms, 0o755);
+        fs::set_permissions(&script_path, perms).unwrap();
+        script_path
+    }
+
+    #[test]
+    fn render_exec_and_run_with_timeout_spawn_a_real_plugin_script() {
+        let plugin_dir = scratch_plugin_dir("spawn");
+        write_script(&plugin_dir, "echo_tool.sh", "#!/bin/sh\ncat >/dev/null\necho -n \"$1\"\n");
+
+        let exec = ExecConfig {
+            binary: "echo_tool.sh".to_string(),
+            args: vec!["{{message}}".to_string()],
+            env: HashMap::new(),
+            working_dir: None,
+            timeout_secs: Some(5),
+        };
+        let p = params(&[("message", serde_json::json!("hello"))]);
+        let rendered = render_exec(&exec, &plugin_dir, &p).expect("render failed");
+        let output = run_with_timeout(&rendered, &p).expect("spawn failed");
+        assert_eq!(String::from_utf8_lossy(&output.stdout), "hello");
+    }
+
+    #[test]
+    fn run_with_timeout_writes_params_as_json_to_child_stdin() {
+        let plugin_dir = scratch_plugin_dir("stdin");
+        write_script(&plugin_dir, "read_stdin.sh", "#!/bin/sh\ncat\n");
+
+        let exec = ExecConfig {
+            binary: "read_stdin.sh".to_string(),
+            args: Vec::new(),
+            env: HashMap::new(),
+            working_dir: None,
+            timeout_secs: Some(5),
+        };
+        let p = params(&[
+            ("device", serde_json::json!("pico0")),
+            ("bus", serde_json::json!(1)),
+        ]);
+        let rendered = render_exec(&exec, &plugin_dir, &p).expect("render failed");
+        let output = run_with_timeout(&rendered, &p).expect("spawn failed");
+
+        let echoed: serde_json::Value =
+            serde_json::from_slice(&output.stdout).expect("child stdout wasn't the stdin JSON");
+        assert_eq!(echoed["device"], serde_json::json!("pico0"));
+        assert_eq!(echoed["bus"], serde_json::json!(1));
+    }
+
+    #[test]
+    fn run_with_timeout_kills_a_child_that_outlives_its_budget() {
+        let rendered = RenderedExec {
+            binary: PathBuf::from("/bin/sleep"),
+            args: vec!["5".to_string()],
+            env: HashMap::new(),
+            working_dir: None,
+            timeout_secs: Some(0),
+        };
+        let err = run_with_timeout(&rendered, &params(&[])).expect_err("expected a timeout");
+        assert_eq!(err.kind(), io::ErrorKind::TimedOut);
+    }
+
+    #[test]
+    fn run_with_timeout_rejects_unschedulable_timeout_without_panicking() {
+        let rendered = RenderedExec {
+            binary: PathBuf::from("/bin/sleep"),
+            args: vec!["0".to_string()],
+            env: HashMap::new(),
+            working_dir: None,
+            timeout_secs: Some(u64::MAX),
+        };
+        let err = run_with_timeout(&rendered, &params(&[]))
+            .expect_err("expected an error, not a panic");
+        assert_eq!(err.kind(), io::ErrorKind::InvalidInput);
+    }
+}
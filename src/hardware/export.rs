@@ -0,0 +1,169 @@
+//! Stable JSON export of the loaded tool registry, for external UIs and CI
+//! tooling. Wrapped in an envelope carrying a `format_version` so consumers
+//! can detect breaking changes, mirroring how `cargo metadata`'s output is
+//! versioned.
+
+use std::fs;
+use std::io::{self, Write};
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+
+use super::loader::WithPath;
+use super::manifest::{HardwareRequirement, ToolManifest, ToolMeta, TransportConfig};
+
+/// Bumped whenever a breaking change is made to the export shape.
+pub const FORMAT_VERSION: u32 = 1;
+
+/// The full exported registry.
+#[derive(Debug, Serialize)]
+pub struct RegistryExport {
+    pub format_version: u32,
+    pub tools: Vec<ExportedTool>,
+}
+
+/// One tool's exported registry entry.
+#[derive(Debug, Serialize)]
+pub struct ExportedTool {
+    pub tool: ToolMeta,
+    /// Absolute path to the tool's binary, resolved relative to the plugin
+    /// directory.
+    pub binary_path: PathBuf,
+    pub transport: Option<TransportConfig>,
+    /// OpenAI-style function-calling schema, as built by
+    /// [`ToolManifest::to_function_schema`].
+    pub parameter_schema: serde_json::Value,
+    pub requirements: Vec<HardwareRequirement>,
+}
+
+/// Builds a `RegistryExport` from a loaded registry.
+pub fn build_export(registry: &[WithPath<ToolManifest>]) -> RegistryExport {
+    let tools = registry
+        .iter()
+        .map(|entry| {
+            let manifest = &entry.inner;
+            ExportedTool {
+                tool: manifest.tool.clone(),
+                binary_path: resolve_binary_path(entry),
+                transport: manifest.transport.clone(),
+                parameter_schema: manifest.to_function_schema(),
+                requirements: manifest.requirements.clone(),
+            }
+        })
+        .collect();
+    RegistryExport {
+        format_version: FORMAT_VERSION,
+        tools,
+    }
+}
+
+fn resolve_binary_path(entry: &WithPath<ToolManifest>) -> PathBuf {
+    let plugin_dir = entry.path.parent().unwrap_or_else(|| Path::new("."));
+    let joined = plugin_dir.join(&entry.inner.exec.binary);
+    if let Ok(canonical) = joined.canonicalize() {
+        return canonical;
+    }
+    std::env::current_dir()
+        .map(|cwd| cwd.join(&joined))
+        .unwrap_or(joined)
+}
+
+/// Serializes `export` as pretty JSON and writes it to `destination`, or to
+/// stdout when `destination` is `None`.
+pub fn write_export(export: &RegistryExport, destination: Option<&Path>) -> io::Result<()> {
+    let json = serde_json::to_string_pretty(export)
+        .map_err(|e| io::Error::new(io::ErrorKind::InvalidData, e))?;
+    match destination {
+        Some(path) => fs::write(path, json),
+        None => writeln!(io::stdout().lock(), "{json}"),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::hardware::manifest::ParameterDef;
+    use std::fs;
+
+    fn scratch_dir(label: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("zeroclaw-export-test-{label}"));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    fn registry_entry(dir: &Path) -> WithPath<ToolManifest> {
+        let manifest = ToolManifest {
+            tool: ToolMeta {
+                name: "i2c_scan".to_string(),
+                version: "1.0.0".to_string(),
+                description: "Scan the I2C bus".to_string(),
+            },
+            exec: super::super::manifest::ExecConfig {
+                binary: "i2c_scan.py".to_string(),
+                args: Vec::new(),
+                env: std::collections::HashMap::new(),
+                working_dir: None,
+                timeout_secs: None,
+            },
+            transport: Some(TransportConfig {
+                preferred: "serial".to_string(),
+                device_required: true,
+                discovery_probe: None,
+            }),
+            parameters: vec![ParameterDef {
+                name: "device".to_string(),
+                r#type: "string".to_string(),
+                description: "Device alias".to_string(),
+                required: true,
+                default: None,
+                r#enum: None,
+                minimum: None,
+                maximum: None,
+                items: None,
+            }],
+            requirements: vec![HardwareRequirement {
+                tag: "I2C-bus".to_string(),
+                optional: false,
+            }],
+            hooks: Vec::new(),
+            dependencies: std::collections::HashMap::new(),
+        };
+        fs::write(dir.join("i2c_scan.py"), "#!/bin/sh\n").unwrap();
+        WithPath {
+            inner: manifest,
+            path: dir.join("tool.toml"),
+        }
+    }
+
+    #[test]
+    fn build_export_resolves_absolute_binary_path_and_schema() {
+        let dir = scratch_dir("build");
+        let registry = vec![registry_entry(&dir)];
+        let export = build_export(&registry);
+        assert_eq!(export.format_version, FORMAT_VERSION);
+        assert_eq!(export.tools.len(), 1);
+        let tool = &export.tools[0];
+        assert_eq!(tool.tool.name, "i2c_scan");
+        assert!(tool.binary_path.is_absolute());
+        assert!(tool.binary_path.ends_with("i2c_scan.py"));
+        assert_eq!(tool.requirements[0].tag, "I2C-bus");
+        assert_eq!(
+            tool.parameter_schema["parameters"]["properties"]["device"]["type"],
+            serde_json::json!("string")
+        );
+    }
+
+    #[test]
+    fn write_export_writes_valid_json_to_a_file() {
+        let dir = scratch_dir("write");
+        let registry = vec![registry_entry(&dir)];
+        let export = build_export(&registry);
+        let out_path = dir.join("registry.json");
+        write_export(&export, Some(&out_path)).expect("write failed");
+        let contents = fs::read_to_string(&out_path).unwrap();
+        let parsed: serde_json::Value = serde_json::from_str(&contents).unwrap();
+        assert_eq!(parsed["format_version"], serde_json::json!(FORMAT_VERSION));
+        assert_eq!(parsed["tools"][0]["tool"]["name"], serde_json::json!("i2c_scan"));
+    }
+}
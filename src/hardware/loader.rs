@@ -0,0 +1,371 @@
+//! Manifest loader — finds every `~/.zeroclaw/tools/<name>/tool.toml`,
+//! parses it, and validates the resulting registry as a whole.
+
+use std::collections::HashSet;
+use std::fmt;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use super::manifest::{ManifestLoadError, ToolManifest};
+
+/// JSON Schema / manifest parameter primitives ZeroClaw understands.
+const KNOWN_PARAMETER_TYPES: &[&str] = &["string", "integer", "number", "boolean", "array"];
+
+/// Transport kinds a manifest may declare as `transport.preferred`.
+const KNOWN_TRANSPORTS: &[&str] = &["serial", "swd", "native", "any"];
+
+/// A parsed value paired with the path it was loaded from, so later errors
+/// and relative `exec.binary` resolution can reference the source file.
+#[derive(Debug)]
+pub struct WithPath<T> {
+    pub inner: T,
+    pub path: PathBuf,
+}
+
+/// Scans `tools_dir` for `<name>/tool.toml` files and parses each one.
+///
+/// Missing or unreadable `tools_dir` yields an empty registry rather than an
+/// error — a fresh install has no plugins installed yet. Per-file read/parse
+/// failures are collected alongside the successfully loaded manifests rather
+/// than aborting the whole scan.
+pub fn load_registry(tools_dir: &Path) -> (Vec<WithPath<ToolManifest>>, Vec<ManifestLoadError>) {
+    let mut manifests = Vec::new();
+    let mut errors = Vec::new();
+    let Ok(entries) = fs::read_dir(tools_dir) else {
+        return (manifests, errors);
+    };
+    for entry in entries.flatten() {
+        let plugin_dir = entry.path();
+        if !plugin_dir.is_dir() {
+            continue;
+        }
+        let manifest_path = plugin_dir.join("tool.toml");
+        if !manifest_path.is_file() {
+            continue;
+        }
+        match ToolManifest::from_path(&manifest_path) {
+            Ok(inner) => manifests.push(WithPath {
+                inner,
+                path: manifest_path,
+            }),
+            Err(e) => errors.push(e),
+        }
+    }
+    (manifests, errors)
+}
+
+/// The default plugin directory: `~/.zeroclaw/tools`.
+pub fn default_tools_dir() -> Option<PathBuf> {
+    std::env::var_os("HOME").map(|home| PathBuf::from(home).join(".zeroclaw").join("tools"))
+}
+
+/// A single problem found while validating a loaded registry.
+#[derive(Debug, PartialEq, Eq)]
+pub struct ValidationError {
+    /// The manifest file the problem was found in.
+    pub path: PathBuf,
+    /// Human-readable description of the problem.
+    pub message: String,
+}
+
+impl fmt::Display for ValidationError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}: {}", self.path.display(), self.message)
+    }
+}
+
+impl std::error::Error for ValidationError {}
+
+/// Validates a loaded registry as a whole, accumulating every problem found
+/// rather than stopping at the first:
+///
+/// - `tool.name` is a valid identifier and unique across the registry
+/// - `tool.version` is valid semver (otherwise
+///   [`crate::hardware::deps::resolve_dependencies`] can only report
+///   dependents' requirements on it as `InvalidVersion`, not check them)
+/// - `transport.preferred`, when set, is one of `serial|swd|native|any`
+/// - every `parameter.type` is a known primitive
+/// - a parameter `default` is only present when `required == false`
+/// - `exec.binary` resolves to a path that exists, relative to the plugin dir
+pub fn validate(registry: &[WithPath<ToolManifest>]) -> Vec<ValidationError> {
+    let mut errors = Vec::new();
+    let mut seen_names = HashSet::new();
+
+    for entry in registry {
+        let manifest = &entry.inner;
+        let path = &entry.path;
+
+        if !is_valid_identifier(&manifest.tool.name) {
+            errors.push(ValidationError {
+                path: path.clone(),
+                message: format!("tool name `{}` is not a valid identifier", manifest.tool.name),
+            });
+        } else if !seen_names.insert(manifest.tool.name.clone()) {
+            errors.push(ValidationError {
+                path: path.clone(),
+                message: format!("duplicate tool name `{}`", manifest.tool.name),
+            });
+        }
+
+        if manifest.tool.semver().is_err() {
+            errors.push(ValidationError {
+                path: path.clone(),
+                message: format!(
+                    "tool.version `{}` is not valid semver",
+                    manifest.tool.version
+                ),
+            });
+        }
+
+        if let Some(transport) = &manifest.transport {
+            if !KNOWN_TRANSPORTS.contains(&transport.preferred.as_str()) {
+                errors.push(ValidationError {
+                    path: path.clone(),
+                    message: format!(
+                        "transport.preferred `{}` must be one of {}",
+                        transport.preferred,
+                        KNOWN_TRANSPORTS.join("|")
+                    ),
+                });
+            }
+        }
+
+        for param in &manifest.parameters {
+            if !KNOWN_PARAMETER_TYPES.contains(&param.r#type.as_str()) {
+                errors.push(ValidationError {
+                    path: path.clone(),
+                    message: format!(
+                        "parameter `{}` has unknown type `{}`",
+                        param.name, param.r#type
+                    ),
+                });
+            }
+            if param.required && param.default.is_some() {
+                errors.push(ValidationError {
+                    path: path.clone(),
+                    message: format!(
+                        "parameter `{}` is required but also declares a default",
+                        param.name
+                    ),
+                });
+            }
+        }
+
+        let plugin_dir = path.parent().unwrap_or_else(|| Path::new("."));
+        let binary_path = plugin_dir.join(&manifest.exec.binary);
+        if !binary_path.exists() {
+            errors.push(ValidationError {
+                path: path.clone(),
+                message: format!("exec.binary `{}` does not exist", binary_path.display()),
+            });
+        }
+    }
+
+    errors
+}
+
+fn is_valid_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    match chars.next() {
+        Some(c) if c.is_ascii_alphabetic() || c == '_' => {}
+        _ => return false,
+    }
+    chars.all(|c| c.is_ascii_alphanumeric() || c == '_')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_plugin(dir: &Path, name: &str, toml: &str, binary_name: Option<&str>) -> PathBuf {
+        let plugin_dir = dir.join(name);
+        fs::create_dir_all(&plugin_dir).unwrap();
+        let manifest_path = plugin_dir.join("tool.toml");
+        fs::write(&manifest_path, toml).unwrap();
+        if let Some(binary_name) = binary_name {
+            fs::write(plugin_dir.join(binary_name), "#!/bin/sh\n").unwrap();
+        }
+        manifest_path
+    }
+
+    fn scratch_dir(label: &str) -> PathBuf {
+        let dir = std::env::temp_dir().join(format!("zeroclaw-loader-test-{label}"));
+        let _ = fs::remove_dir_all(&dir);
+        fs::create_dir_all(&dir).unwrap();
+        dir
+    }
+
+    #[test]
+    fn load_registry_finds_tool_toml_in_each_plugin_dir() {
+        let dir = scratch_dir("load");
+        write_plugin(
+            &dir,
+            "i2c_scan",
+            r#"
+[tool]
+name = "i2c_scan"
+version = "1.0.0"
+description = "Scan the I2C bus"
+
+[exec]
+binary = "i2c_scan.py"
+"#,
+            Some("i2c_scan.py"),
+        );
+        let (manifests, errors) = load_registry(&dir);
+        assert!(errors.is_empty());
+        assert_eq!(manifests.len(), 1);
+        assert_eq!(manifests[0].inner.tool.name, "i2c_scan");
+        assert!(manifests[0].path.ends_with("i2c_scan/tool.toml"));
+    }
+
+    #[test]
+    fn load_registry_tolerates_missing_tools_dir() {
+        let (manifests, errors) = load_registry(Path::new("/nonexistent/zeroclaw/tools"));
+        assert!(manifests.is_empty());
+        assert!(errors.is_empty());
+    }
+
+    #[test]
+    fn load_registry_collects_parse_errors_without_aborting() {
+        let dir = scratch_dir("parse-error");
+        write_plugin(&dir, "broken", "not valid toml [[[", None);
+        write_plugin(
+            &dir,
+            "ok_tool",
+            r#"
+[tool]
+name = "ok_tool"
+version = "1.0.0"
+description = "Fine"
+
+[exec]
+binary = "ok_tool"
+"#,
+            Some("ok_tool"),
+        );
+        let (manifests, errors) = load_registry(&dir);
+        assert_eq!(manifests.len(), 1);
+        assert_eq!(errors.len(), 1);
+    }
+
+    #[test]
+    fn validate_reports_duplicate_names_unknown_transport_and_missing_binary() {
+        let dir = scratch_dir("validate");
+        let path_a = write_plugin(
+            &dir,
+            "dup_a",
+            r#"
+[tool]
+name = "dup"
+version = "1.0.0"
+description = "A"
+
+[exec]
+binary = "missing_binary"
+
+[transport]
+preferred = "carrier-pigeon"
+device_required = false
+"#,
+            None,
+        );
+        let path_b = write_plugin(
+            &dir,
+            "dup_b",
+            r#"
+[tool]
+name = "dup"
+version = "1.0.0"
+description = "B"
+
+[exec]
+binary = "run"
+"#,
+            Some("run"),
+        );
+        let registry = vec![
+            WithPath {
+                inner: ToolManifest::from_path(&path_a).unwrap(),
+                path: path_a,
+            },
+            WithPath {
+                inner: ToolManifest::from_path(&path_b).unwrap(),
+                path: path_b,
+            },
+        ];
+        let errors = validate(&registry);
+        assert!(errors.iter().any(|e| e.message.contains("duplicate tool name")));
+        assert!(errors
+            .iter()
+            .any(|e| e.message.contains("transport.preferred")));
+        assert!(errors.iter().any(|e| e.message.contains("does not exist")));
+    }
+
+    #[test]
+    fn validate_rejects_required_parameter_with_default() {
+        let dir = scratch_dir("validate-default");
+        let path = write_plugin(
+            &dir,
+            "conflicting",
+            r#"
+[tool]
+name = "conflicting"
+version = "1.0.0"
+description = "Bad default"
+
+[exec]
+binary = "run"
+
+[[parameters]]
+name = "bus"
+type = "integer"
+description = "Bus number"
+required = true
+default = 0
+"#,
+            Some("run"),
+        );
+        let registry = vec![WithPath {
+            inner: ToolManifest::from_path(&path).unwrap(),
+            path,
+        }];
+        let errors = validate(&registry);
+        assert!(errors
+            .iter()
+            .any(|e| e.message.contains("also declares a default")));
+    }
+
+    #[test]
+    fn validate_rejects_unparsable_tool_version() {
+        let dir = scratch_dir("validate-version");
+        let path = write_plugin(
+            &dir,
+            "bad_version",
+            r#"
+[tool]
+name = "bad_version"
+version = "not-a-semver"
+description = "Bad version string"
+
+[exec]
+binary = "run"
+"#,
+            Some("run"),
+        );
+        let registry = vec![WithPath {
+            inner: ToolManifest::from_path(&path).unwrap(),
+            path,
+        }];
+        let errors = validate(&registry);
+        assert!(errors.iter().any(|e| e.message.contains("not valid semver")));
+    }
+
+    #[test]
+    fn is_valid_identifier_rejects_leading_digit_and_dashes() {
+        assert!(is_valid_identifier("i2c_scan"));
+        assert!(!is_valid_identifier("2fast"));
+        assert!(!is_valid_identifier("i2c-scan"));
+        assert!(!is_valid_identifier(""));
+    }
+}
@@ -31,7 +31,12 @@
 //! default     = 0
 //! ```
 
-use serde::Deserialize;
+use std::collections::HashMap;
+use std::fmt;
+use std::path::{Path, PathBuf};
+
+use semver::VersionReq;
+use serde::{Deserialize, Serialize};
 
 /// Full plugin manifest — parsed from `tool.toml`.
 #[derive(Debug, Deserialize)]
@@ -45,10 +50,100 @@ pub struct ToolManifest {
     /// Parameter definitions used to build the JSON Schema for the LLM.
     #[serde(default)]
     pub parameters: Vec<ParameterDef>,
+    /// Free-form hardware capabilities this tool needs resolved via discovery.
+    #[serde(default)]
+    pub requirements: Vec<HardwareRequirement>,
+    /// Commands run before (`"pre"`) and after (`"post"`) the tool itself,
+    /// e.g. resetting a board over SWD or flushing logs.
+    #[serde(default)]
+    pub hooks: Vec<HookConfig>,
+    /// Other installed plugins this tool requires, by name, with a semver
+    /// range each must satisfy. Checked by
+    /// [`crate::hardware::deps::resolve_dependencies`].
+    #[serde(default)]
+    pub dependencies: HashMap<String, VersionReq>,
+}
+
+/// Error reading or parsing a manifest from disk.
+#[derive(Debug)]
+pub enum ManifestLoadError {
+    /// The file could not be read.
+    Io(PathBuf, std::io::Error),
+    /// The file's contents were not a valid manifest.
+    Parse(PathBuf, toml::de::Error),
+}
+
+impl fmt::Display for ManifestLoadError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Self::Io(path, e) => write!(f, "{}: {e}", path.display()),
+            Self::Parse(path, e) => write!(f, "{}: {e}", path.display()),
+        }
+    }
+}
+
+impl std::error::Error for ManifestLoadError {}
+
+impl ToolManifest {
+    /// Parses a manifest from raw `tool.toml` contents.
+    pub fn from_slice(raw: &str) -> Result<Self, toml::de::Error> {
+        toml::from_str(raw)
+    }
+
+    /// Reads and parses a manifest from `path`.
+    pub fn from_path(path: &Path) -> Result<Self, ManifestLoadError> {
+        let raw = std::fs::read_to_string(path)
+            .map_err(|e| ManifestLoadError::Io(path.to_path_buf(), e))?;
+        Self::from_slice(&raw).map_err(|e| ManifestLoadError::Parse(path.to_path_buf(), e))
+    }
+
+    /// Builds an OpenAI-style function-calling descriptor from this
+    /// manifest's `tool` metadata and `parameters`:
+    ///
+    /// ```json
+    /// {
+    ///   "name": "...",
+    ///   "description": "...",
+    ///   "parameters": { "type": "object", "properties": {...}, "required": [...] }
+    /// }
+    /// ```
+    pub fn to_function_schema(&self) -> serde_json::Value {
+        let mut properties = serde_json::Map::new();
+        let mut required = Vec::new();
+        for param in &self.parameters {
+            properties.insert(param.name.clone(), param.to_schema_property());
+            if param.required {
+                required.push(serde_json::Value::String(param.name.clone()));
+            }
+        }
+        serde_json::json!({
+            "name": self.tool.name,
+            "description": self.tool.description,
+            "parameters": {
+                "type": "object",
+                "properties": properties,
+                "required": required,
+            },
+        })
+    }
+
+    /// Fills in `params` with each parameter's declared `default`, for any
+    /// parameter the caller omitted. Call this before
+    /// [`crate::hardware::exec::render_exec`] so a template like
+    /// `{{duty}}` resolves even when the LLM didn't supply `duty`.
+    pub fn fill_defaults(&self, params: &mut serde_json::Map<String, serde_json::Value>) {
+        for param in &self.parameters {
+            if !params.contains_key(&param.name) {
+                if let Some(default) = &param.default {
+                    params.insert(param.name.clone(), default.clone());
+                }
+            }
+        }
+    }
 }
 
 /// Tool identity metadata.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct ToolMeta {
     /// Unique tool name, used as the function-call key by the LLM.
     pub name: String,
@@ -58,6 +153,15 @@ pub struct ToolMeta {
     pub description: String,
 }
 
+impl ToolMeta {
+    /// Parses `version` as a [`semver::Version`]. Used by
+    /// [`crate::hardware::loader::validate`] and, at resolution time, by
+    /// [`crate::hardware::deps::resolve_dependencies`].
+    pub fn semver(&self) -> Result<semver::Version, semver::Error> {
+        semver::Version::parse(&self.version)
+    }
+}
+
 /// Execution configuration — how ZeroClaw spawns the tool.
 #[derive(Debug, Deserialize)]
 pub struct ExecConfig {
@@ -66,18 +170,62 @@ pub struct ExecConfig {
     /// Can be a Python script (`"tool.py"`), a shell script (`"run.sh"`),
     /// a compiled binary (`"i2c_scan"`), or any executable.
     pub binary: String,
+    /// Command-line arguments, with `{{param}}` (and `{{device.port}}`-style
+    /// nested) placeholders substituted from the resolved parameter map.
+    /// See [`crate::hardware::exec::render_template`].
+    #[serde(default)]
+    pub args: Vec<String>,
+    /// Extra environment variables for the child process; values are
+    /// templated the same way as `args`.
+    #[serde(default)]
+    pub env: HashMap<String, String>,
+    /// Working directory for the child process, relative to the plugin
+    /// directory unless absolute. Templated the same way as `args`.
+    pub working_dir: Option<String>,
+    /// Kills the child if it runs longer than this many seconds.
+    pub timeout_secs: Option<u64>,
 }
 
 /// Optional transport hint for the tool.
 ///
 /// When present, ZeroClaw will prefer the named transport kind
 /// and can enforce device presence before calling the tool.
-#[derive(Debug, Deserialize)]
+#[derive(Debug, Clone, Deserialize, Serialize)]
 pub struct TransportConfig {
     /// Preferred transport kind: `"serial"` | `"swd"` | `"native"` | `"any"`.
     pub preferred: String,
     /// Whether the tool requires a hardware device to be connected.
     pub device_required: bool,
+    /// Path to a probe binary/script, run before invocation, whose stdout is
+    /// a JSON object mapping each declared requirement tag to a device
+    /// descriptor. See [`crate::hardware::discovery`].
+    pub discovery_probe: Option<String>,
+}
+
+/// A command run before or after the tool's main invocation.
+#[derive(Debug, Deserialize)]
+pub struct HookConfig {
+    /// When to run this hook: `"pre"` | `"post"`.
+    pub when: String,
+    /// Command to execute, relative to the plugin directory.
+    pub command: String,
+    /// Arguments, templated the same way as `exec.args`.
+    #[serde(default)]
+    pub args: Vec<String>,
+}
+
+/// A free-form hardware capability a tool needs resolved before it can run,
+/// e.g. `"I2C-bus"`, `"UART-loopback"`, `"SWD-target"`.
+///
+/// Resolved against a [`crate::hardware::discovery::DiscoveryReport`] by
+/// [`crate::hardware::discovery::match_requirements`].
+#[derive(Debug, Clone, Deserialize, Serialize)]
+pub struct HardwareRequirement {
+    /// Capability tag, matched against keys in the discovery report.
+    pub tag: String,
+    /// If true, the tool can run without this capability being resolved.
+    #[serde(default)]
+    pub optional: bool,
 }
 
 /// A single parameter definition for a plugin tool.
@@ -85,7 +233,7 @@ pub struct TransportConfig {
 pub struct ParameterDef {
     /// Parameter name (matches the JSON key passed to the tool via stdin).
     pub name: String,
-    /// JSON Schema primitive type: `"string"` | `"integer"` | `"boolean"`.
+    /// JSON Schema primitive type: `"string"` | `"integer"` | `"number"` | `"boolean"` | `"array"`.
     #[serde(rename = "type")]
     pub r#type: String,
     /// Human-readable description shown to the LLM.
@@ -94,6 +242,61 @@ pub struct ParameterDef {
     pub required: bool,
     /// Optional default value serialized as a JSON Value.
     pub default: Option<serde_json::Value>,
+    /// Restricts the value to one of a fixed set, emitted as a JSON Schema `enum`.
+    #[serde(default)]
+    pub r#enum: Option<Vec<serde_json::Value>>,
+    /// Inclusive lower bound for numeric types.
+    #[serde(default)]
+    pub minimum: Option<f64>,
+    /// Inclusive upper bound for numeric types.
+    #[serde(default)]
+    pub maximum: Option<f64>,
+    /// Element definition for `"array"`-typed parameters.
+    #[serde(default)]
+    pub items: Option<Box<ParameterDef>>,
+}
+
+impl ParameterDef {
+    /// Builds the JSON Schema `properties` entry for this parameter.
+    fn to_schema_property(&self) -> serde_json::Value {
+        let mut prop = serde_json::Map::new();
+        prop.insert(
+            "type".to_string(),
+            serde_json::Value::String(json_schema_type(&self.r#type)),
+        );
+        prop.insert(
+            "description".to_string(),
+            serde_json::Value::String(self.description.clone()),
+        );
+        if let Some(default) = &self.default {
+            prop.insert("default".to_string(), default.clone());
+        }
+        if let Some(values) = &self.r#enum {
+            prop.insert("enum".to_string(), serde_json::Value::Array(values.clone()));
+        }
+        if let Some(minimum) = self.minimum {
+            prop.insert("minimum".to_string(), serde_json::json!(minimum));
+        }
+        if let Some(maximum) = self.maximum {
+            prop.insert("maximum".to_string(), serde_json::json!(maximum));
+        }
+        if let Some(items) = &self.items {
+            prop.insert("items".to_string(), items.to_schema_property());
+        }
+        serde_json::Value::Object(prop)
+    }
+}
+
+/// Translates a manifest's parameter type string into a JSON Schema type name.
+fn json_schema_type(r#type: &str) -> String {
+    match r#type {
+        "integer" => "integer",
+        "boolean" => "boolean",
+        "array" => "array",
+        "number" => "number",
+        _ => "string",
+    }
+    .to_string()
 }
 
 #[cfg(test)]
@@ -191,4 +394,202 @@ binary = "noop"
         let m: ToolManifest = toml::from_str(raw).expect("parse failed");
         assert!(m.parameters.is_empty());
     }
+
+    #[test]
+    fn manifest_parses_hardware_requirements() {
+        let raw = r#"
+[tool]
+name        = "flash_firmware"
+version     = "1.0.0"
+description = "Flash firmware over SWD"
+
+[exec]
+binary = "flash_firmware"
+
+[transport]
+preferred       = "swd"
+device_required = true
+discovery_probe = "probe.py"
+
+[[requirements]]
+tag = "SWD-target"
+
+[[requirements]]
+tag      = "UART-loopback"
+optional = true
+"#;
+        let m: ToolManifest = toml::from_str(raw).expect("parse failed");
+        let transport = m.transport.as_ref().expect("transport missing");
+        assert_eq!(transport.discovery_probe.as_deref(), Some("probe.py"));
+        assert_eq!(m.requirements.len(), 2);
+        assert_eq!(m.requirements[0].tag, "SWD-target");
+        assert!(!m.requirements[0].optional);
+        assert!(m.requirements[1].optional);
+    }
+
+    #[test]
+    fn manifest_parses_templated_exec_and_hooks() {
+        let raw = r#"
+[tool]
+name        = "flash_firmware"
+version     = "1.0.0"
+description = "Flash firmware over SWD"
+
+[exec]
+binary       = "flash_firmware"
+args         = ["--port", "{{device.port}}", "--image", "{{image}}"]
+working_dir  = "{{device.port}}"
+timeout_secs = 30
+
+[exec.env]
+LOG_LEVEL = "debug"
+
+[[hooks]]
+when    = "pre"
+command = "swd_reset"
+args    = ["{{device.port}}"]
+
+[[hooks]]
+when    = "post"
+command = "flush_logs"
+"#;
+        let m: ToolManifest = toml::from_str(raw).expect("parse failed");
+        assert_eq!(m.exec.args, vec!["--port", "{{device.port}}", "--image", "{{image}}"]);
+        assert_eq!(m.exec.env.get("LOG_LEVEL"), Some(&"debug".to_string()));
+        assert_eq!(m.exec.timeout_secs, Some(30));
+        assert_eq!(m.hooks.len(), 2);
+        assert_eq!(m.hooks[0].when, "pre");
+        assert_eq!(m.hooks[1].command, "flush_logs");
+        assert!(m.hooks[1].args.is_empty());
+    }
+
+    #[test]
+    fn manifest_parses_dependencies_table() {
+        let raw = r#"
+[tool]
+name        = "flash_firmware"
+version     = "1.0.0"
+description = "Flash firmware over SWD"
+
+[exec]
+binary = "flash_firmware"
+
+[dependencies]
+swd_reset = ">=1.2"
+"#;
+        let m: ToolManifest = toml::from_str(raw).expect("parse failed");
+        let req = m.dependencies.get("swd_reset").expect("dependency missing");
+        assert!(req.matches(&semver::Version::parse("1.2.0").unwrap()));
+        assert!(!req.matches(&semver::Version::parse("1.1.0").unwrap()));
+        assert_eq!(m.tool.semver().unwrap(), semver::Version::parse("1.0.0").unwrap());
+    }
+
+    #[test]
+    fn manifest_dependencies_default_to_empty() {
+        let m: ToolManifest = toml::from_str(MINIMAL_TOML).expect("parse failed");
+        assert!(m.dependencies.is_empty());
+    }
+
+    #[test]
+    fn fill_defaults_inserts_only_missing_defaulted_params() {
+        let m: ToolManifest = toml::from_str(FULL_TOML).expect("parse failed");
+        let mut params = serde_json::Map::new();
+        params.insert("device".to_string(), serde_json::json!("pico0"));
+        params.insert("pin".to_string(), serde_json::json!(4));
+        m.fill_defaults(&mut params);
+        assert_eq!(params["duty"], serde_json::json!(50));
+        assert_eq!(params["device"], serde_json::json!("pico0"));
+    }
+
+    #[test]
+    fn fill_defaults_does_not_override_a_supplied_value() {
+        let m: ToolManifest = toml::from_str(FULL_TOML).expect("parse failed");
+        let mut params = serde_json::Map::new();
+        params.insert("device".to_string(), serde_json::json!("pico0"));
+        params.insert("pin".to_string(), serde_json::json!(4));
+        params.insert("duty".to_string(), serde_json::json!(75));
+        m.fill_defaults(&mut params);
+        assert_eq!(params["duty"], serde_json::json!(75));
+    }
+
+    #[test]
+    fn from_slice_matches_toml_from_str() {
+        let m = ToolManifest::from_slice(MINIMAL_TOML).expect("parse failed");
+        assert_eq!(m.tool.name, "i2c_scan");
+    }
+
+    #[test]
+    fn manifest_requirements_default_to_empty_vec() {
+        let m: ToolManifest = toml::from_str(MINIMAL_TOML).expect("parse failed");
+        assert!(m.requirements.is_empty());
+    }
+
+    #[test]
+    fn to_function_schema_covers_required_and_default_params() {
+        let m: ToolManifest = toml::from_str(FULL_TOML).expect("parse failed");
+        let schema = m.to_function_schema();
+        assert_eq!(schema["name"], serde_json::json!("pwm_set"));
+        assert_eq!(
+            schema["parameters"]["properties"]["pin"]["type"],
+            serde_json::json!("integer")
+        );
+        assert_eq!(
+            schema["parameters"]["properties"]["duty"]["default"],
+            serde_json::json!(50)
+        );
+        let required = schema["parameters"]["required"].as_array().unwrap();
+        assert!(required.contains(&serde_json::json!("device")));
+        assert!(required.contains(&serde_json::json!("pin")));
+        assert!(!required.contains(&serde_json::json!("duty")));
+    }
+
+    #[test]
+    fn to_function_schema_emits_enum_bounds_and_array_items() {
+        let raw = r#"
+[tool]
+name        = "pwm_set"
+version     = "1.0.0"
+description = "Set PWM duty cycle on a pin"
+
+[exec]
+binary = "pwm_set"
+
+[[parameters]]
+name        = "mode"
+type        = "string"
+description = "Drive mode"
+required    = true
+enum        = ["push-pull", "open-drain"]
+
+[[parameters]]
+name        = "duty"
+type        = "integer"
+description = "Duty cycle 0-100"
+required    = true
+minimum     = 0
+maximum     = 100
+
+[[parameters]]
+name        = "pins"
+type        = "array"
+description = "Pins to drive"
+required    = true
+
+[parameters.items]
+name        = "pin"
+type        = "integer"
+description = "Pin number"
+required    = true
+"#;
+        let m: ToolManifest = toml::from_str(raw).expect("parse failed");
+        let schema = m.to_function_schema();
+        let props = &schema["parameters"]["properties"];
+        assert_eq!(
+            props["mode"]["enum"],
+            serde_json::json!(["push-pull", "open-drain"])
+        );
+        assert_eq!(props["duty"]["minimum"], serde_json::json!(0.0));
+        assert_eq!(props["duty"]["maximum"], serde_json::json!(100.0));
+        assert_eq!(props["pins"]["items"]["type"], serde_json::json!("integer"));
+    }
 }
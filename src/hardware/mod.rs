@@ -0,0 +1,8 @@
+//! Hardware-facing plugin support: manifest schema, discovery, and matching.
+
+pub mod deps;
+pub mod discovery;
+pub mod exec;
+pub mod export;
+pub mod loader;
+pub mod manifest;